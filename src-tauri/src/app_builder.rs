@@ -0,0 +1,91 @@
+use std::error::Error;
+
+use tauri::{App, Manager, Wry};
+
+type SetupHook = Box<dyn FnOnce(&mut App<Wry>) -> Result<(), Box<dyn Error>> + Send>;
+
+/// Wraps `tauri::Builder` so callers can register their own setup hook
+/// without rewriting the entry point in `lib.rs`. The command set is the
+/// fixed `generate_handler!` list in `run`; `AppBuilder` has no method for
+/// adding commands.
+pub struct AppBuilder {
+    setup: Option<SetupHook>,
+}
+
+impl AppBuilder {
+    pub fn new() -> Self {
+        Self { setup: None }
+    }
+
+    /// Overrides the default "show main window after setup" step.
+    pub fn setup<F>(mut self, setup: F) -> Self
+    where
+        F: FnOnce(&mut App<Wry>) -> Result<(), Box<dyn Error>> + Send + 'static,
+    {
+        self.setup = Some(Box::new(setup));
+        self
+    }
+
+    pub fn run(self) {
+        let setup = self.setup.unwrap_or_else(|| Box::new(default_setup));
+
+        let builder = tauri::Builder::default()
+            .plugin(tauri_plugin_opener::init())
+            .plugin(tauri_plugin_notification::init());
+
+        #[cfg(not(mobile))]
+        let builder = builder.plugin(crate::hotkeys::plugin());
+
+        builder
+            .invoke_handler(tauri::generate_handler![
+                crate::commands::greet,
+                crate::commands::notify,
+                crate::commands::get_hotkeys,
+                crate::commands::set_hotkey,
+            ])
+            .setup(move |app| {
+                // Unconditional so a caller-supplied `setup` hook can't
+                // silently drop hotkey registration by replacing the default.
+                #[cfg(not(mobile))]
+                crate::hotkeys::init(&app.handle())?;
+
+                setup(app)
+            })
+            .run(tauri::generate_context!())
+            .expect("error while running tauri application");
+    }
+}
+
+impl Default for AppBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn default_setup(app: &mut App<Wry>) -> Result<(), Box<dyn Error>> {
+    let splashscreen = app.get_webview_window("splashscreen");
+    let main_window = app.get_webview_window("main");
+
+    tauri::async_runtime::spawn(async move {
+        init().await;
+
+        // Mobile has no splashscreen window to tear down; the main
+        // window's lifecycle is managed by the OS, so just reveal it.
+        #[cfg(not(mobile))]
+        if let Some(splashscreen) = splashscreen {
+            splashscreen.close().unwrap();
+        }
+        #[cfg(mobile)]
+        let _ = splashscreen;
+
+        if let Some(main_window) = main_window {
+            main_window.show().unwrap();
+        }
+    });
+
+    Ok(())
+}
+
+/// Placeholder for slow startup work (DB open, config load, etc.) that should
+/// run behind the splashscreen instead of blocking the main window's reveal.
+async fn init() {}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use tauri::AppHandle;
+use tauri_plugin_notification::{NotificationExt, PermissionState};
+
+#[cfg(not(mobile))]
+use tauri::Manager;
+
+#[cfg(not(mobile))]
+use crate::hotkeys::{self, HotkeyState};
+
+#[tauri::command]
+pub fn greet(name: &str) -> String {
+    format!("Hello, {name}! You've been greeted from Rust!")
+}
+
+/// Sends an OS-level notification, requesting permission first if it hasn't
+/// been granted yet. Works on desktop and mobile targets alike.
+#[tauri::command]
+pub fn notify(app: AppHandle, title: String, body: String) -> Result<(), String> {
+    let notification = app.notification();
+
+    let mut permission_state = notification.permission_state().map_err(|e| e.to_string())?;
+    if permission_state != PermissionState::Granted {
+        permission_state = notification.request_permission().map_err(|e| e.to_string())?;
+    }
+    if permission_state != PermissionState::Granted {
+        return Err("notification permission was not granted".into());
+    }
+
+    notification
+        .builder()
+        .title(title)
+        .body(body)
+        .show()
+        .map_err(|e| e.to_string())
+}
+
+/// Returns the registered hotkeys, or just the one matching `query_id` if given.
+///
+/// Global shortcuts aren't supported on mobile, so this returns an empty map there.
+#[tauri::command]
+pub fn get_hotkeys(app: AppHandle, query_id: Option<String>) -> HashMap<String, Vec<String>> {
+    #[cfg(not(mobile))]
+    {
+        app.state::<HotkeyState>().snapshot(query_id.as_deref())
+    }
+    #[cfg(mobile)]
+    {
+        let _ = (app, query_id);
+        HashMap::new()
+    }
+}
+
+/// Updates a single hotkey's combo, persisting it and re-registering it with
+/// the OS. Fails (instead of panicking) if the combo is invalid or already
+/// claimed by another application, or if called on mobile where global
+/// shortcuts aren't supported.
+#[tauri::command]
+pub fn set_hotkey(
+    app: AppHandle,
+    id: String,
+    combo: Vec<String>,
+) -> Result<HashMap<String, Vec<String>>, String> {
+    #[cfg(not(mobile))]
+    {
+        hotkeys::set(&app, id.clone(), combo)?;
+        Ok(app.state::<HotkeyState>().snapshot(Some(&id)))
+    }
+    #[cfg(mobile)]
+    {
+        let _ = (app, id, combo);
+        Err("global hotkeys are not supported on mobile".to_string())
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+const CONFIG_FILE_NAME: &str = "hotkeys.json";
+
+/// The persisted id -> key-combo map, e.g. `{"toggle_window": ["CmdOrCtrl", "Shift", "E"]}`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct HotkeyConfig {
+    combos: HashMap<String, Vec<String>>,
+}
+
+impl HotkeyConfig {
+    fn defaults() -> Self {
+        Self {
+            combos: HashMap::from([(
+                "toggle_window".to_string(),
+                vec!["CmdOrCtrl".to_string(), "Shift".to_string(), "E".to_string()],
+            )]),
+        }
+    }
+
+    /// Loads the config from the app data dir, writing out defaults if the
+    /// file doesn't exist yet.
+    fn load(app: &AppHandle) -> Result<Self, String> {
+        Self::load_from(&config_path(app)?)
+    }
+
+    fn save(&self, app: &AppHandle) -> Result<(), String> {
+        self.save_to(&config_path(app)?)
+    }
+
+    fn load_from(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            let config = Self::defaults();
+            config.save_to(path)?;
+            return Ok(config);
+        }
+
+        let raw = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        serde_json::from_str::<Self>(&raw).map_err(|e| e.to_string())
+    }
+
+    fn save_to(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let raw = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(path, raw).map_err(|e| e.to_string())
+    }
+}
+
+fn config_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    Ok(dir.join(CONFIG_FILE_NAME))
+}
+
+/// Tracks the currently registered combos so they can be looked up from the
+/// global shortcut handler and re-registered from the `set_hotkey` command.
+#[derive(Default)]
+pub struct HotkeyState {
+    combos: Mutex<HashMap<String, Vec<String>>>,
+    shortcuts: Mutex<HashMap<String, Shortcut>>,
+}
+
+impl HotkeyState {
+    pub fn snapshot(&self, query_id: Option<&str>) -> HashMap<String, Vec<String>> {
+        let combos = self.combos.lock().unwrap();
+        match query_id {
+            Some(id) => combos
+                .get(id)
+                .map(|combo| HashMap::from([(id.to_string(), combo.clone())]))
+                .unwrap_or_default(),
+            None => combos.clone(),
+        }
+    }
+}
+
+fn parse_combo(combo: &[String]) -> Result<Shortcut, String> {
+    combo.join("+").parse::<Shortcut>().map_err(|e| e.to_string())
+}
+
+/// Registers (or re-registers) a single hotkey, returning the failure
+/// instead of panicking so callers can report it back to the frontend.
+fn register_combo(app: &AppHandle, id: &str, combo: &[String]) -> Result<(), String> {
+    let state = app.state::<HotkeyState>();
+    let shortcut = parse_combo(combo)?;
+
+    if let Some(previous) = state.shortcuts.lock().unwrap().remove(id) {
+        let _ = app.global_shortcut().unregister(previous);
+    }
+
+    app.global_shortcut()
+        .register(shortcut)
+        .map_err(|e| e.to_string())?;
+
+    state.shortcuts.lock().unwrap().insert(id.to_string(), shortcut);
+    state.combos.lock().unwrap().insert(id.to_string(), combo.to_vec());
+    Ok(())
+}
+
+/// Loads the config file and registers every combo. A combo that fails to
+/// register doesn't abort startup; it's reported to the frontend via a
+/// `hotkey-register-error` event (the same event-emission path the press
+/// handler below already uses) instead of panicking.
+pub fn init(app: &AppHandle) -> Result<(), String> {
+    app.manage(HotkeyState::default());
+
+    let config = HotkeyConfig::load(app)?;
+    for (id, combo) in &config.combos {
+        if let Err(error) = register_combo(app, id, combo) {
+            let _ = app.emit(
+                "hotkey-register-error",
+                RegisterError {
+                    id: id.clone(),
+                    combo: combo.clone(),
+                    error,
+                },
+            );
+        }
+    }
+    Ok(())
+}
+
+#[derive(Clone, Serialize)]
+struct RegisterError {
+    id: String,
+    combo: Vec<String>,
+    error: String,
+}
+
+/// Updates a single hotkey, persisting the new config and re-registering the
+/// combo. Returns an error instead of panicking if the combo can't be parsed
+/// or claimed by the OS.
+pub fn set(app: &AppHandle, id: String, combo: Vec<String>) -> Result<(), String> {
+    register_combo(app, &id, &combo)?;
+
+    let state = app.state::<HotkeyState>();
+    let config = HotkeyConfig {
+        combos: state.combos.lock().unwrap().clone(),
+    };
+    config.save(app)
+}
+
+/// Builds the global-shortcut plugin, wiring its press handler to emit a
+/// frontend event named after the matching hotkey id.
+pub fn plugin<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
+    tauri_plugin_global_shortcut::Builder::new()
+        .with_handler(|app, shortcut, event| {
+            if event.state != ShortcutState::Pressed {
+                return;
+            }
+            let state = app.state::<HotkeyState>();
+            let id = state
+                .shortcuts
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|(_, registered)| *registered == shortcut)
+                .map(|(id, _)| id.clone());
+
+            if let Some(id) = id {
+                let _ = app.emit(&id, ());
+            }
+        })
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_round_trips_through_save_and_load() {
+        let dir = std::env::temp_dir().join(format!(
+            "emperor-hotkeys-test-{:?}",
+            std::thread::current().id()
+        ));
+        let path = dir.join(CONFIG_FILE_NAME);
+        let _ = fs::remove_dir_all(&dir);
+
+        let loaded = HotkeyConfig::load_from(&path).expect("defaults should save and load");
+        assert_eq!(loaded, HotkeyConfig::defaults());
+
+        let mut written = loaded;
+        written
+            .combos
+            .insert("toggle_window".to_string(), vec!["Alt".to_string(), "Space".to_string()]);
+        written.save_to(&path).expect("save should succeed");
+
+        let reloaded = HotkeyConfig::load_from(&path).expect("load should succeed");
+        assert_eq!(reloaded, written);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn parse_combo_rejects_an_empty_combo() {
+        assert!(parse_combo(&[]).is_err());
+    }
+
+    #[test]
+    fn snapshot_filters_by_query_id() {
+        let state = HotkeyState::default();
+        state
+            .combos
+            .lock()
+            .unwrap()
+            .insert("a".to_string(), vec!["A".to_string()]);
+        state
+            .combos
+            .lock()
+            .unwrap()
+            .insert("b".to_string(), vec!["B".to_string()]);
+
+        assert_eq!(state.snapshot(None).len(), 2);
+
+        let filtered = state.snapshot(Some("a"));
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered.get("a"), Some(&vec!["A".to_string()]));
+
+        assert!(state.snapshot(Some("missing")).is_empty());
+    }
+}
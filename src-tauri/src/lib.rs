@@ -1,16 +1,13 @@
-use tauri::Manager;
+mod app_builder;
+mod commands;
+#[cfg(not(mobile))]
+mod hotkeys;
+#[cfg(mobile)]
+mod mobile;
+
+pub use app_builder::AppBuilder;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
-        .plugin(tauri_plugin_opener::init())
-        .setup(|app| {
-            // Show the main window after setup is complete
-            if let Some(window) = app.get_webview_window("main") {
-                window.show().unwrap();
-            }
-            Ok(())
-        })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+    AppBuilder::new().run();
 }
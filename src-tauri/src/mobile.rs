@@ -0,0 +1,6 @@
+//! Platform-specific setup for Android/iOS builds.
+//!
+//! Desktop builds enter through `main.rs`; mobile builds enter through the
+//! `tauri::mobile_entry_point`-annotated `run()` in `lib.rs`, which is the
+//! only thing the OS calls directly. This module is the place to hang any
+//! mobile-only setup (permissions, lifecycle hooks) as it's needed.